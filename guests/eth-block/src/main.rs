@@ -15,25 +15,33 @@
 #![no_main]
 
 use risc0_zkvm::guest::env;
-use zeth_lib::{
-    builder::{BlockBuilderStrategy, EthereumStrategy},
-    consts::ETH_MAINNET_CHAIN_SPEC,
-};
+use zeth_lib::builder::{BlockBuilderStrategy, EthereumStrategy};
 use zeth_lib::output::BlockBuildOutput;
 
 risc0_zkvm::guest::entry!(main);
 
 pub fn main() {
-    // Read the input previous block and transaction data
+    // Read the input previous block and transaction data. The chain spec travels
+    // inside the input rather than as a hardcoded constant, so the guest enforces
+    // exactly the fork schedule the host resolved during preflight.
     let input = env::read();
+    let chain_spec = input.chain_spec.clone();
     // Build the resulting block
-    let mut output = EthereumStrategy::build_from(&ETH_MAINNET_CHAIN_SPEC, input)
+    let mut output = EthereumStrategy::build_from(&chain_spec, input)
         .expect("Failed to build the resulting block");
-    // Abridge successful construction results
-    if let BlockBuildOutput::SUCCESS { new_block_hash, new_block_head, new_block_state } = &mut output {
+    // Abridge successful construction results, but keep the chain spec digest so a
+    // verifier can tell which network this proof is for.
+    if let BlockBuildOutput::SUCCESS {
+        new_block_hash,
+        new_block_head,
+        new_block_state,
+        chain_spec_digest,
+    } = &mut output
+    {
         let trie_root = core::mem::replace(new_block_state, new_block_head.state_root.into());
         // Leak memory, save cycles
         core::mem::forget(trie_root);
+        *chain_spec_digest = chain_spec.digest();
     }
     // Output the construction result
     env::commit(&output);
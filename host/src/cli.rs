@@ -0,0 +1,115 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser};
+
+/// Arguments shared by every subcommand: which blocks to fetch/build and where to
+/// fetch them from.
+#[derive(Clone, Debug, Args)]
+pub struct CoreArgs {
+    /// URL of the Ethereum RPC node.
+    #[clap(long)]
+    pub eth_rpc_url: Option<String>,
+    /// URL of the Optimism RPC node.
+    #[clap(long)]
+    pub op_rpc_url: Option<String>,
+    /// Directory to cache RPC responses in.
+    #[clap(long)]
+    pub cache: Option<PathBuf>,
+    /// Number of the first block to build/derive.
+    #[clap(long, short)]
+    pub block_number: u64,
+    /// Number of blocks to build/derive starting at `block_number`.
+    #[clap(long, default_value_t = 1)]
+    pub block_count: u64,
+    /// Path to a JSON chain-spec file describing the network to prove.
+    ///
+    /// When omitted, zeth falls back to its hardcoded `ETH_MAINNET_CHAIN_SPEC` /
+    /// `OP_MAINNET_CHAIN_SPEC` constants when fetching blocks, and to
+    /// `OPTIMISM_CHAIN_SPEC` for OP derivation and composition. Supplying this
+    /// lets zeth prove an OP-stack L2 or testnet it doesn't ship a constant for,
+    /// without a source change or recompile.
+    #[clap(long)]
+    pub chain_spec: Option<PathBuf>,
+    /// Write the derived/built blocks out as standard RLP (header + transactions +
+    /// uncles) to this path, so they can be fed into any execution client to
+    /// independently confirm the hash the proof commits to.
+    #[clap(long)]
+    pub export_rlp: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct ExecutorArgs {
+    #[clap(long)]
+    pub local_exec: Option<usize>,
+    #[clap(long)]
+    pub profile: bool,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct BuildArgs {
+    #[clap(flatten)]
+    pub core_args: CoreArgs,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct RunArgs {
+    #[clap(flatten)]
+    pub core_args: CoreArgs,
+    #[clap(flatten)]
+    pub exec_args: ExecutorArgs,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct ProveArgs {
+    #[clap(flatten)]
+    pub core_args: CoreArgs,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct VerifyArgs {
+    #[clap(flatten)]
+    pub core_args: CoreArgs,
+    #[clap(long)]
+    pub bonsai_receipt_uuid: Option<String>,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct OpInfoArgs {
+    #[clap(flatten)]
+    pub core_args: CoreArgs,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub enum Cli {
+    Build(BuildArgs),
+    Run(RunArgs),
+    Prove(ProveArgs),
+    Verify(VerifyArgs),
+    OpInfo(OpInfoArgs),
+}
+
+impl Cli {
+    pub fn core_args(&self) -> &CoreArgs {
+        match self {
+            Cli::Build(args) => &args.core_args,
+            Cli::Run(args) => &args.core_args,
+            Cli::Prove(args) => &args.core_args,
+            Cli::Verify(args) => &args.core_args,
+            Cli::OpInfo(args) => &args.core_args,
+        }
+    }
+}
@@ -18,8 +18,8 @@ use anyhow::Context;
 use log::info;
 use zeth_guests::*;
 use zeth_lib::{
-    builder::OptimismStrategy,
-    consts::{Network, OP_MAINNET_CHAIN_SPEC},
+    builder::{BlockBuilderStrategy, EthereumStrategy, OptimismStrategy},
+    consts::{spec_file::load_chain_spec, ChainSpec, Network, ETH_MAINNET_CHAIN_SPEC, OP_MAINNET_CHAIN_SPEC},
     host::{preflight::Preflight, rpc_db::RpcDb},
     input::Input,
     optimism::{
@@ -31,7 +31,7 @@ use zeth_lib::{
 };
 use zeth_primitives::{
     block::Header,
-    transactions::optimism::OptimismTxEssence,
+    transactions::{ethereum::EthereumTxEssence, optimism::OptimismTxEssence},
     tree::{MerkleMountainRange, MerkleProof},
 };
 
@@ -41,11 +41,24 @@ use crate::{
     operations::{execute, maybe_prove, verify_bonsai_receipt},
 };
 
+/// Resolves the [`ChainSpec`] a run should use: the spec loaded from
+/// `--chain-spec <path>` if one was given, or the hardcoded fallback otherwise. Using
+/// this everywhere `fetch_op_blocks`/`derive_rollup_blocks` need a spec guarantees the
+/// same runtime-constructed spec reaches `DeriveMachine::new` and, via the guest
+/// `Input`, the proof itself.
+fn resolve_chain_spec(core_args: &CoreArgs, fallback: &ChainSpec) -> anyhow::Result<ChainSpec> {
+    match &core_args.chain_spec {
+        Some(path) => load_chain_spec(path),
+        None => Ok(fallback.clone()),
+    }
+}
+
 async fn fetch_op_blocks(
     core_args: &CoreArgs,
     block_number: u64,
     block_count: u64,
 ) -> anyhow::Result<Vec<Input<OptimismTxEssence>>> {
+    let chain_spec = resolve_chain_spec(core_args, &OP_MAINNET_CHAIN_SPEC)?;
     let mut op_blocks = vec![];
     for i in 0..block_count {
         let block_number = block_number + i;
@@ -53,18 +66,24 @@ async fn fetch_op_blocks(
             cache_file_path(dir, &Network::Optimism.to_string(), block_number, "json.gz")
         });
         let rpc_url = core_args.op_rpc_url.clone();
+        let chain_spec = chain_spec.clone();
         // Collect block building data
         let preflight_result = tokio::task::spawn_blocking(move || {
-            OptimismStrategy::run_preflight(
-                OP_MAINNET_CHAIN_SPEC.clone(),
-                rpc_cache,
-                rpc_url,
-                block_number,
-            )
+            OptimismStrategy::run_preflight(chain_spec, rpc_cache, rpc_url, block_number)
         })
         .await?
         .context("preflight failed")?;
 
+        if let Some(export_path) = &core_args.export_rlp {
+            zeth_lib::export::export_block(
+                export_path,
+                &preflight_result.block.header,
+                &preflight_result.block.transactions,
+                &preflight_result.block.ommers,
+            )
+            .context("could not export derived block as RLP")?;
+        }
+
         // Create the guest input from [Init]
         let input = preflight_result
             .clone()
@@ -77,6 +96,103 @@ async fn fetch_op_blocks(
     Ok(op_blocks)
 }
 
+async fn fetch_eth_blocks(
+    core_args: &CoreArgs,
+    block_number: u64,
+    block_count: u64,
+) -> anyhow::Result<Vec<Input<EthereumTxEssence>>> {
+    let chain_spec = resolve_chain_spec(core_args, &ETH_MAINNET_CHAIN_SPEC)?;
+    let mut eth_blocks = vec![];
+    for i in 0..block_count {
+        let block_number = block_number + i;
+        let rpc_cache = core_args.cache.as_ref().map(|dir| {
+            cache_file_path(dir, &Network::Ethereum.to_string(), block_number, "json.gz")
+        });
+        let rpc_url = core_args.eth_rpc_url.clone();
+        let chain_spec = chain_spec.clone();
+        // Collect block building data
+        let preflight_result = tokio::task::spawn_blocking(move || {
+            EthereumStrategy::run_preflight(chain_spec, rpc_cache, rpc_url, block_number)
+        })
+        .await?
+        .context("preflight failed")?;
+
+        if let Some(export_path) = &core_args.export_rlp {
+            zeth_lib::export::export_block(
+                export_path,
+                &preflight_result.block.header,
+                &preflight_result.block.transactions,
+                &preflight_result.block.ommers,
+            )
+            .context("could not export built block as RLP")?;
+        }
+
+        // Create the guest input from [Init]
+        let input = preflight_result
+            .clone()
+            .try_into()
+            .context("invalid preflight data")?;
+
+        eth_blocks.push(input);
+    }
+
+    Ok(eth_blocks)
+}
+
+/// Builds each Ethereum block in `core_args`'s range. Unlike OP derivation there is no
+/// cross-block batching to do, so each block is fetched, built in-memory once, and run
+/// through the executor/prover independently; there is no second in-memory re-run to
+/// cross-check against, as `derive_rollup_blocks` does.
+pub async fn build_eth_blocks(cli: Cli, file_reference: &String) -> anyhow::Result<()> {
+    info!("Fetching data ...");
+    let core_args = cli.core_args().clone();
+    let chain_spec = resolve_chain_spec(&core_args, &ETH_MAINNET_CHAIN_SPEC)?;
+    let eth_blocks = fetch_eth_blocks(&core_args, core_args.block_number, core_args.block_count).await?;
+
+    for input in eth_blocks {
+        let output = EthereumStrategy::build_from(&chain_spec, input.clone())
+            .context("Failed to build the resulting block")?;
+
+        match &cli {
+            Cli::Build(..) => {}
+            Cli::Run(run_args) => {
+                execute(
+                    &input,
+                    run_args.exec_args.local_exec,
+                    run_args.exec_args.profile,
+                    ETH_BLOCK_ELF,
+                    &output,
+                    file_reference,
+                );
+            }
+            Cli::Prove(..) => {
+                maybe_prove(
+                    &cli,
+                    &input,
+                    ETH_BLOCK_ELF,
+                    &output,
+                    Default::default(),
+                    file_reference,
+                    None,
+                );
+            }
+            Cli::Verify(verify_args) => {
+                verify_bonsai_receipt(
+                    ETH_BLOCK_ID.into(),
+                    &output,
+                    verify_args.bonsai_receipt_uuid.clone(),
+                    None,
+                )?;
+            }
+            Cli::OpInfo(..) => {
+                unreachable!()
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn derive_rollup_blocks(cli: Cli, file_reference: &String) -> anyhow::Result<()> {
     info!("Fetching data ...");
     let core_args = cli.core_args().clone();
@@ -86,36 +202,40 @@ pub async fn derive_rollup_blocks(cli: Cli, file_reference: &String) -> anyhow::
         core_args.block_count,
     )
     .await?;
+    let chain_spec = resolve_chain_spec(&core_args, &OPTIMISM_CHAIN_SPEC)?;
 
-    let (derive_input, output) = tokio::task::spawn_blocking(move || {
-        let derive_input = DeriveInput {
-            db: RpcDb::new(
-                core_args.eth_rpc_url.clone(),
-                core_args.op_rpc_url.clone(),
-                core_args.cache.clone(),
-            ),
-            op_head_block_no: core_args.block_number,
-            op_derive_block_count: core_args.block_count,
-            op_blocks: op_blocks.clone(),
-        };
-        let mut derive_machine = DeriveMachine::new(&OPTIMISM_CHAIN_SPEC, derive_input)
-            .context("Could not create derive machine")?;
-        let derive_output = derive_machine.derive().context("could not derive")?;
-        let derive_input_mem = DeriveInput {
-            db: derive_machine.derive_input.db.get_mem_db(),
-            op_head_block_no: core_args.block_number,
-            op_derive_block_count: core_args.block_count,
-            op_blocks,
-        };
-        let out: anyhow::Result<_> = Ok((derive_input_mem, derive_output));
-        out
+    let (derive_input, output) = tokio::task::spawn_blocking({
+        let chain_spec = chain_spec.clone();
+        move || {
+            let derive_input = DeriveInput {
+                db: RpcDb::new(
+                    core_args.eth_rpc_url.clone(),
+                    core_args.op_rpc_url.clone(),
+                    core_args.cache.clone(),
+                ),
+                op_head_block_no: core_args.block_number,
+                op_derive_block_count: core_args.block_count,
+                op_blocks: op_blocks.clone(),
+            };
+            let mut derive_machine = DeriveMachine::new(&chain_spec, derive_input)
+                .context("Could not create derive machine")?;
+            let derive_output = derive_machine.derive().context("could not derive")?;
+            let derive_input_mem = DeriveInput {
+                db: derive_machine.derive_input.db.get_mem_db(),
+                op_head_block_no: core_args.block_number,
+                op_derive_block_count: core_args.block_count,
+                op_blocks,
+            };
+            let out: anyhow::Result<_> = Ok((derive_input_mem, derive_output));
+            out
+        }
     })
     .await?
     .context("preflight failed")?;
 
     info!("Running from memory ...");
     {
-        let output_mem = DeriveMachine::new(&OPTIMISM_CHAIN_SPEC, derive_input.clone())
+        let output_mem = DeriveMachine::new(&chain_spec, derive_input.clone())
             .context("Could not create derive machine")?
             .derive()
             .unwrap();
@@ -174,6 +294,7 @@ pub async fn compose_derived_rollup_blocks(
     file_reference: &String,
 ) -> anyhow::Result<()> {
     let core_args = cli.core_args().clone();
+    let chain_spec = resolve_chain_spec(&core_args, &OPTIMISM_CHAIN_SPEC)?;
     // OP Composition
     info!("Fetching data ...");
     let mut lift_queue = Vec::new();
@@ -188,52 +309,55 @@ pub async fn compose_derived_rollup_blocks(
         let op_head_block_no = core_args.block_number + op_block_index;
         let op_blocks = fetch_op_blocks(&core_args, op_head_block_no + 1, composition_size).await?;
 
-        let (input, output, chain) = tokio::task::spawn_blocking(move || {
-            let derive_input = DeriveInput {
-                db,
-                op_head_block_no: core_args.block_number + op_block_index,
-                op_derive_block_count: composition_size,
-                op_blocks: op_blocks.clone(),
-            };
-            let mut derive_machine = DeriveMachine::new(&OPTIMISM_CHAIN_SPEC, derive_input)
-                .expect("Could not create derive machine");
-            let eth_head_no = derive_machine.op_batcher.state.epoch.number;
-            let eth_head = derive_machine
-                .derive_input
-                .db
-                .get_eth_block_header(eth_head_no)
-                .context("could not fetch eth head")?;
-            let derive_output = derive_machine.derive().context("could not derive")?;
-            let eth_tail = derive_machine
-                .derive_input
-                .db
-                .get_eth_block_header(derive_output.eth_tail.0)
-                .context("could not fetch eth tail")?;
-            let mut eth_chain = vec![eth_head];
-            for block_no in (eth_head_no + 1)..eth_tail.number {
-                let eth_block = derive_machine
+        let (input, output, chain) = tokio::task::spawn_blocking({
+            let chain_spec = chain_spec.clone();
+            move || {
+                let derive_input = DeriveInput {
+                    db,
+                    op_head_block_no: core_args.block_number + op_block_index,
+                    op_derive_block_count: composition_size,
+                    op_blocks: op_blocks.clone(),
+                };
+                let mut derive_machine = DeriveMachine::new(&chain_spec, derive_input)
+                    .expect("Could not create derive machine");
+                let eth_head_no = derive_machine.op_batcher.state.epoch.number;
+                let eth_head = derive_machine
                     .derive_input
                     .db
-                    .get_eth_block_header(block_no)
-                    .context("could not fetch eth block")?;
-                eth_chain.push(eth_block);
-            }
-            eth_chain.push(eth_tail);
+                    .get_eth_block_header(eth_head_no)
+                    .context("could not fetch eth head")?;
+                let derive_output = derive_machine.derive().context("could not derive")?;
+                let eth_tail = derive_machine
+                    .derive_input
+                    .db
+                    .get_eth_block_header(derive_output.eth_tail.0)
+                    .context("could not fetch eth tail")?;
+                let mut eth_chain = vec![eth_head];
+                for block_no in (eth_head_no + 1)..eth_tail.number {
+                    let eth_block = derive_machine
+                        .derive_input
+                        .db
+                        .get_eth_block_header(block_no)
+                        .context("could not fetch eth block")?;
+                    eth_chain.push(eth_block);
+                }
+                eth_chain.push(eth_tail);
 
-            let derive_input_mem = DeriveInput {
-                db: derive_machine.derive_input.db.get_mem_db(),
-                op_head_block_no: core_args.block_number + op_block_index,
-                op_derive_block_count: composition_size,
-                op_blocks,
-            };
-            let out: anyhow::Result<_> = Ok((derive_input_mem, derive_output, eth_chain));
-            out
+                let derive_input_mem = DeriveInput {
+                    db: derive_machine.derive_input.db.get_mem_db(),
+                    op_head_block_no: core_args.block_number + op_block_index,
+                    op_derive_block_count: composition_size,
+                    op_blocks,
+                };
+                let out: anyhow::Result<_> = Ok((derive_input_mem, derive_output, eth_chain));
+                out
+            }
         })
         .await??;
 
         info!("Deriving ...");
         {
-            let output_mem = DeriveMachine::new(&OPTIMISM_CHAIN_SPEC, input.clone())
+            let output_mem = DeriveMachine::new(&chain_spec, input.clone())
                 .expect("Could not create derive machine")
                 .derive()
                 .unwrap();
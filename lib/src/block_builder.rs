@@ -0,0 +1,59 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The header-chain bookkeeping every `crate::builder` strategy threads through while
+//! it builds or derives a sequence of blocks: advancing the chain spec's consensus
+//! engine and checking each new header is validly sealed on top of its parent, via
+//! [`crate::finalization::finalize_header`]. A strategy owns the actual state
+//! transition (EVM execution, trie updates); this only tracks what depends on the
+//! consensus engine rather than on execution, so it isn't duplicated per strategy.
+
+use anyhow::Result;
+use zeth_primitives::block::Header;
+
+use crate::{
+    consensus::{ConsensusEngine, ConsensusState},
+    consts::ChainSpec,
+    finalization::finalize_header,
+};
+
+/// Carries the running [`ConsensusState`] across a sequence of headers built or
+/// derived on the same chain, so e.g. Clique's signer-vote tally and recent-signer
+/// window persist from one header to the next instead of resetting per block.
+pub struct BlockBuilder<'a> {
+    chain_spec: &'a ChainSpec,
+    consensus_state: ConsensusState,
+}
+
+impl<'a> BlockBuilder<'a> {
+    /// Starts a new builder for `chain_spec`, seeding its consensus state from the
+    /// spec's genesis configuration (e.g. the Clique genesis signer set).
+    pub fn new(chain_spec: &'a ChainSpec) -> Self {
+        BlockBuilder {
+            chain_spec,
+            consensus_state: chain_spec.engine().initial_state(),
+        }
+    }
+
+    /// Validates that `header` extends `parent` and is validly sealed under this
+    /// chain's [`ConsensusEngine`], advancing the builder's consensus state in place.
+    /// Must be called once per header, in order.
+    pub fn finalize_header(&mut self, header: &Header, parent: &Header) -> Result<()> {
+        finalize_header(header, parent, self.engine(), &mut self.consensus_state)
+    }
+
+    fn engine(&self) -> &ConsensusEngine {
+        self.chain_spec.engine()
+    }
+}
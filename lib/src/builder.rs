@@ -0,0 +1,194 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The [`BlockBuilderStrategy`] trait every guest/host entry point builds blocks
+//! through, so header-chain validation (via [`crate::block_builder::BlockBuilder`])
+//! runs exactly once per header regardless of which strategy is executing, instead of
+//! being duplicated -- or skipped -- per call site.
+
+use anyhow::{ensure, Result};
+use zeth_primitives::{transactions::ethereum::EthereumTxEssence, transactions::TxEssence, B256};
+
+use crate::{block_builder::BlockBuilder, consts::ChainSpec, input::Input, output::BlockBuildOutput};
+
+/// If `chain_spec` was loaded from a chain spec file with a genesis allocation and
+/// `input`'s parent header is block 0, checks that `input` is actually building on top
+/// of that genesis rather than some other state a malicious or buggy host supplied: the
+/// parent's state root and the pre-state accounts must match the chain spec exactly.
+/// Inputs whose parent is any later block are not checked against the genesis.
+fn verify_genesis<E: TxEssence>(chain_spec: &ChainSpec, input: &Input<E>) -> Result<()> {
+    let Some(genesis) = chain_spec.genesis() else {
+        return Ok(());
+    };
+    if input.parent_header.number != 0 {
+        return Ok(());
+    }
+    ensure!(
+        input.parent_header.state_root == genesis.state_root,
+        "genesis parent header's state root does not match the chain spec's genesis"
+    );
+    ensure!(
+        &input.pre_state == chain_spec.accounts(),
+        "genesis pre-state does not match the chain spec's preinstalled accounts"
+    );
+    Ok(())
+}
+
+/// Builds a sequence of blocks from an [`Input`], rejecting the whole input if any
+/// header in it fails [`BlockBuilder::finalize_header`] -- e.g. a Clique-sealed chain
+/// whose header carries an unauthorized or missing signature.
+pub trait BlockBuilderStrategy {
+    type TxEssence: TxEssence;
+
+    fn build_from(chain_spec: &ChainSpec, input: Input<Self::TxEssence>) -> Result<BlockBuildOutput>;
+}
+
+/// Builds plain Ethereum blocks, including Ethereum-derivative chains sealed with an
+/// alternate [`crate::consensus::ConsensusEngine`] such as Clique.
+///
+/// Only the header chain and seals are validated: transactions are not executed, and
+/// the reported post-state root is the one the last block's header states. An input
+/// with no blocks yields [`BlockBuildOutput::FAILURE`].
+pub struct EthereumStrategy;
+
+impl BlockBuilderStrategy for EthereumStrategy {
+    type TxEssence = EthereumTxEssence;
+
+    fn build_from(chain_spec: &ChainSpec, input: Input<Self::TxEssence>) -> Result<BlockBuildOutput> {
+        verify_genesis(chain_spec, &input)?;
+
+        let mut block_builder = BlockBuilder::new(chain_spec);
+        let mut parent = input.parent_header;
+        for block in &input.blocks {
+            block_builder.finalize_header(&block.header, &parent)?;
+            parent = block.header.clone();
+        }
+
+        let Some(last_block) = input.blocks.last() else {
+            return Ok(BlockBuildOutput::FAILURE);
+        };
+
+        Ok(BlockBuildOutput::SUCCESS {
+            new_block_hash: last_block.header.hash(),
+            new_block_head: last_block.header.clone(),
+            new_block_state: last_block.header.state_root,
+            chain_spec_digest: chain_spec.digest(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use zeth_primitives::{block::Block, Address, U256};
+
+    use super::*;
+    use crate::{
+        consensus::{clique::CliqueConfig, ConsensusEngine},
+        consts::MIN_SPEC_ID,
+    };
+
+    #[test]
+    fn rejects_block_with_unauthorized_clique_seal() {
+        let chain_spec = ChainSpec::new_single(
+            "test".to_string(),
+            1,
+            MIN_SPEC_ID,
+            Default::default(),
+        )
+        .with_engine(ConsensusEngine::Clique(CliqueConfig {
+            period: 0,
+            epoch: 0,
+            // An all-zero seal is not a valid secp256k1 signature, so no member of
+            // this signer set can have produced the seal on the block below.
+            genesis_signers: vec![Address::repeat_byte(0x11)],
+        }));
+
+        let parent_header = zeth_primitives::block::Header::default();
+        let sealed_header = zeth_primitives::block::Header {
+            number: parent_header.number + 1,
+            timestamp: parent_header.timestamp + 1,
+            difficulty: U256::from(2u64),
+            // 32-byte vanity prefix followed by a 65-byte all-zero seal: long enough to
+            // reach signer recovery, which then rejects the zero signature.
+            extra_data: vec![0u8; 32 + 65].into(),
+            ..Default::default()
+        };
+
+        let input = Input {
+            chain_spec: chain_spec.clone(),
+            parent_header,
+            pre_state: BTreeMap::new(),
+            blocks: vec![Block {
+                header: sealed_header,
+                transactions: vec![],
+                ommers: vec![],
+            }],
+        };
+
+        let result = EthereumStrategy::build_from(&chain_spec, input);
+        assert!(
+            result.is_err(),
+            "a header sealed by an unauthorized Clique signer must be rejected by the build path"
+        );
+    }
+
+    #[test]
+    fn rejects_pre_state_that_does_not_match_the_genesis_allocation() {
+        let genesis = crate::consts::spec_file::GenesisStub {
+            state_root: B256::repeat_byte(0x42),
+            gas_limit: 30_000_000,
+            timestamp: 0,
+            extra_data: Default::default(),
+            base_fee_per_gas: None,
+        };
+        let accounts = BTreeMap::from([(
+            Address::repeat_byte(0x01),
+            crate::consts::spec_file::GenesisAccount {
+                balance: U256::from(1u64),
+                nonce: 0,
+                code: None,
+                storage: BTreeMap::new(),
+            },
+        )]);
+        let chain_spec = ChainSpec::new_single(
+            "test".to_string(),
+            1,
+            MIN_SPEC_ID,
+            Default::default(),
+        )
+        .with_genesis(genesis.clone(), accounts);
+
+        let parent_header = zeth_primitives::block::Header {
+            number: 0,
+            state_root: genesis.state_root,
+            ..Default::default()
+        };
+
+        // Empty pre-state does not match the single preinstalled account above.
+        let input = Input {
+            chain_spec: chain_spec.clone(),
+            parent_header,
+            pre_state: BTreeMap::new(),
+            blocks: vec![],
+        };
+
+        let result = EthereumStrategy::build_from(&chain_spec, input);
+        assert!(
+            result.is_err(),
+            "pre-state that omits a preinstalled genesis account must be rejected"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serde types for zeth's conformance fixtures.
+//!
+//! These mirror the key names of the `BlockchainTests` fixtures published by
+//! `ethereum/tests` / `execution-spec-tests` (`genesisBlockHeader`, `pre`, `blocks`,
+//! `network`, `postStateHash`) closely enough that a real upstream fixture file can be
+//! dropped in unmodified, but zeth does not implement the full upstream schema: there is
+//! no support for the inline `postState` account-diff form (only the `postStateHash`
+//! digest form), and `FixtureBlock::rlp` is required rather than the upstream convention
+//! of omitting it on the one block a unit expects to fail to decode. A unit that needs
+//! either of those is simply not representable here yet.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use revm::primitives::SpecId;
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{block::Header, Address, Bytes, B256, U256};
+
+use crate::{
+    consts::{spec_file::parse_fork_name, ChainSpec},
+    input::Input,
+};
+
+/// A whole fixture file: `{ "testName": { ... }, ... }`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockchainTestFixture(pub BTreeMap<String, BlockchainTestUnit>);
+
+/// A single test case. Unlike zeth's own chain-spec files (which carry a whole fork
+/// schedule), a fixture unit pins exactly one fork: upstream generates a separate unit
+/// per fork it wants to cover, rather than one unit with a fork-indexed result table.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockchainTestUnit {
+    #[serde(rename = "genesisBlockHeader")]
+    pub genesis_block_header: Header,
+    pub pre: BTreeMap<Address, PreStateAccount>,
+    pub blocks: Vec<FixtureBlock>,
+    /// The single hardfork this unit was generated for, e.g. `"Shanghai"`.
+    pub network: String,
+    /// Expected state root after every block in `blocks` has been built, meaningless
+    /// when any block in `blocks` sets `expect_exception`.
+    #[serde(rename = "postStateHash")]
+    pub post_state_hash: B256,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PreStateAccount {
+    pub balance: U256,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub code: Bytes,
+    #[serde(default)]
+    pub storage: BTreeMap<B256, B256>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FixtureBlock {
+    /// The block, RLP-encoded exactly as it appears on the wire.
+    pub rlp: Bytes,
+    /// Set when the fixture expects this block to be rejected; holds the upstream
+    /// reason string (zeth does not attempt to match it, only that a rejection
+    /// occurred).
+    #[serde(rename = "expectException", default)]
+    pub expect_exception: Option<String>,
+}
+
+impl BlockchainTestUnit {
+    /// Builds the [`Input`] `EthereumStrategy::build_from` expects: the pre-state
+    /// allocation and parent header from the fixture, plus the fixture's block
+    /// sequence decoded from RLP.
+    pub fn build_input(&self) -> Result<Input<zeth_primitives::transactions::ethereum::EthereumTxEssence>> {
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|block| {
+                zeth_primitives::block::Block::decode(&mut block.rlp.as_ref())
+                    .context("could not decode fixture block RLP")
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Input::from_fixture(self.genesis_block_header.clone(), self.pre.clone(), blocks)
+    }
+
+    /// The fork this unit was generated for.
+    pub fn fork(&self) -> Result<SpecId> {
+        parse_fork_name(&self.network)
+    }
+
+    /// Whether the fixture expects the build to fail: upstream attaches
+    /// `expectException` to the one block it expects to be rejected, rather than to the
+    /// unit as a whole.
+    pub fn expects_exception(&self) -> bool {
+        self.blocks.iter().any(|block| block.expect_exception.is_some())
+    }
+
+    /// The [`ChainSpec`] matching the fixture's fork, with every prior and current
+    /// hardfork active from genesis -- fixtures are single-block-range, so there is no
+    /// activation schedule to reconstruct, only the active fork itself.
+    pub fn chain_spec(&self) -> Result<ChainSpec> {
+        Ok(ChainSpec::new_single(
+            "conformance".to_string(),
+            self.genesis_block_header.chain_id().unwrap_or(1),
+            self.fork()?,
+            Default::default(),
+        ))
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs the standard Ethereum execution-spec-tests "blockchain test" JSON fixtures
+//! directly through [`crate::builder::EthereumStrategy`], host-side and without a
+//! zkVM. "State test" fixtures are not supported.
+//!
+//! Proving against live RPC data and comparing `build_from`'s output against an
+//! in-memory re-run (as `derive_rollup_blocks` does) only ever exercises whatever
+//! mainnet blocks happen to get fetched. These fixtures instead pin a pre-state
+//! allocation, a parent header, and a sequence of blocks to an expected post-state
+//! root (or an expected-invalid flag), so regressions in header and seal validation
+//! (`finalization`) are caught deterministically offline, the same way client
+//! conformance suites are run against Docker simulators. `EthereumStrategy` does not
+//! execute transactions, so the post-state root compared is the one the last block's
+//! header states.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use revm::primitives::SpecId;
+use zeth_primitives::B256;
+
+pub mod fixture;
+
+use fixture::{BlockchainTestFixture, BlockchainTestUnit};
+
+/// The outcome of running a single fixture, so the caller can report a useful mismatch
+/// instead of a single boolean.
+#[derive(Debug)]
+pub enum ConformanceResult {
+    /// The fixture expected the chain to build successfully and it did, with the
+    /// expected post-state root.
+    Passed,
+    /// The fixture expected one of its blocks to be rejected, and the build failed.
+    PassedAsInvalid,
+    /// The build succeeded but produced a different state root than the fixture
+    /// expects.
+    StateRootMismatch { expected: B256, actual: B256 },
+    /// The fixture expected one of its blocks to be rejected, but the build succeeded.
+    UnexpectedlyValid,
+    /// The fixture expected success, but the build failed.
+    UnexpectedlyInvalid { reason: String },
+}
+
+impl ConformanceResult {
+    pub fn is_pass(&self) -> bool {
+        matches!(self, ConformanceResult::Passed | ConformanceResult::PassedAsInvalid)
+    }
+}
+
+/// Runs a single blockchain-test unit, unless its fork is not in `forks`, in which case
+/// it is skipped -- zeth does not implement every historical fork, so fixtures for e.g.
+/// Frontier are skipped rather than reported as failures.
+pub fn run_unit(unit: &BlockchainTestUnit, forks: &[SpecId]) -> Result<Option<ConformanceResult>> {
+    let fork = unit.fork().context("could not determine fixture unit's fork")?;
+    if !forks.contains(&fork) {
+        return Ok(None);
+    }
+
+    let chain_spec = unit.chain_spec()?;
+    // A fixture unit that expects a block to be rejected can fail while decoding its
+    // RLP, while building it, or by building to `BlockBuildOutput::FAILURE`; all three
+    // count as "the build failed" from here.
+    let build_result = unit
+        .build_input()
+        .and_then(|input| crate::builder::EthereumStrategy::build_from(&chain_spec, input))
+        .and_then(|output| match output {
+            crate::output::BlockBuildOutput::FAILURE => {
+                Err(anyhow::anyhow!("build produced BlockBuildOutput::FAILURE"))
+            }
+            output => Ok(output),
+        });
+
+    let result = match (build_result, unit.expects_exception()) {
+        (Ok(output), false) => {
+            let actual = output.state_root();
+            if actual == unit.post_state_hash {
+                ConformanceResult::Passed
+            } else {
+                ConformanceResult::StateRootMismatch {
+                    expected: unit.post_state_hash,
+                    actual,
+                }
+            }
+        }
+        (Ok(_), true) => ConformanceResult::UnexpectedlyValid,
+        (Err(_), true) => ConformanceResult::PassedAsInvalid,
+        (Err(err), false) => ConformanceResult::UnexpectedlyInvalid {
+            reason: err.to_string(),
+        },
+    };
+    Ok(Some(result))
+}
+
+/// Loads every `*.json` blockchain-test fixture under `dir` (recursively), keeping only
+/// units whose fork is in `forks`.
+pub fn load_fixtures(dir: impl AsRef<Path>, forks: &[SpecId]) -> Result<Vec<BlockchainTestUnit>> {
+    let mut units = Vec::new();
+    for entry in walk_json_files(dir.as_ref())? {
+        let raw = std::fs::read_to_string(&entry)
+            .with_context(|| format!("could not read fixture {}", entry.display()))?;
+        let fixture: BlockchainTestFixture = serde_json::from_str(&raw)
+            .with_context(|| format!("could not parse fixture {}", entry.display()))?;
+        for unit in fixture.0.into_values() {
+            if unit.fork().is_ok_and(|fork| forks.contains(&fork)) {
+                units.push(unit);
+            }
+        }
+    }
+    Ok(units)
+}
+
+fn walk_json_files(dir: &Path) -> Result<Vec<std::path::PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("could not read {}", dir.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(walk_json_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
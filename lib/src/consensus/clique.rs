@@ -0,0 +1,243 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [EIP-225](https://eips.ethereum.org/EIPS/eip-225) Clique proof-of-authority seal
+//! verification: recover the block's signer from the secp256k1 signature packed into
+//! `extraData`, check it against the current authorized-signer set, enforce the
+//! in-turn/out-of-turn `difficulty` rule, and apply any signer-add/remove vote the
+//! header carries.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use anyhow::{ensure, Context, Result};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{block::Header, keccak::keccak, Address, U256};
+
+/// Length, in bytes, of the recoverable secp256k1 signature Clique appends to the end
+/// of `extraData`.
+const EXTRA_SEAL_LEN: usize = 65;
+/// Length, in bytes, of the arbitrary vanity prefix every Clique `extraData` starts
+/// with, before the seal and (on checkpoint blocks) the signer list.
+const EXTRA_VANITY_LEN: usize = 32;
+/// Length, in bytes, of a single packed signer address in a checkpoint block's
+/// `extraData` signer list.
+const ADDRESS_LEN: usize = 20;
+/// `difficulty` of a block sealed by the signer whose turn it is.
+const DIFF_IN_TURN: u64 = 2;
+/// `difficulty` of a block sealed out of turn.
+const DIFF_NO_TURN: u64 = 1;
+
+/// Per-chain Clique parameters, taken from the chain spec.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CliqueConfig {
+    /// Minimum number of seconds between two consecutive blocks.
+    pub period: u64,
+    /// Number of blocks after which to checkpoint and reset the pending votes.
+    pub epoch: u64,
+    /// The genesis authorized-signer set, as packed into the genesis `extraData`
+    /// checkpoint.
+    pub genesis_signers: Vec<Address>,
+}
+
+/// A pending vote to add or remove a signer, proposed by `proposer` and accumulated
+/// over the epoch until it reaches a majority of the current signer set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Vote {
+    proposer: Address,
+    subject: Address,
+    authorize: bool,
+}
+
+/// Mutable Clique state threaded across headers.
+#[derive(Clone, Debug)]
+pub struct CliqueState {
+    config: CliqueConfig,
+    signers: BTreeSet<Address>,
+    /// Signers who sealed one of the last `signers.len() / 2` blocks; a signer in this
+    /// window may not seal again until it slides out, so no signer seals twice within
+    /// `signers.len() / 2 + 1` consecutive blocks.
+    recents: VecDeque<Address>,
+    votes: Vec<Vote>,
+}
+
+impl CliqueState {
+    pub fn new(config: CliqueConfig, genesis_signers: Vec<Address>) -> Self {
+        CliqueState {
+            config,
+            signers: genesis_signers.into_iter().collect(),
+            recents: VecDeque::new(),
+            votes: Vec::new(),
+        }
+    }
+
+    fn recent_signer_limit(&self) -> usize {
+        self.signers.len() / 2 + 1
+    }
+
+    /// The signer whose turn it is to seal the block at `number`, by round-robin over
+    /// the (ordered) current signer set.
+    fn in_turn_signer(&self, number: u64) -> Option<&Address> {
+        if self.signers.is_empty() {
+            return None;
+        }
+        let index = (number as usize) % self.signers.len();
+        self.signers.iter().nth(index)
+    }
+}
+
+/// Recovers the address that produced the signature appended to `header.extra_data`,
+/// over `keccak256(rlp(header-without-signature))`.
+///
+/// The last [`EXTRA_SEAL_LEN`] bytes of `extraData` are the signature; everything
+/// before them (the vanity prefix and any signer-list checkpoint) is part of the
+/// signed payload, exactly like every other header field.
+pub fn recover_signer(header: &Header) -> Result<Address> {
+    ensure!(
+        header.extra_data.len() >= EXTRA_VANITY_LEN + EXTRA_SEAL_LEN,
+        "extraData too short to contain a vanity prefix and a Clique seal"
+    );
+    let (signed_extra, seal) = header
+        .extra_data
+        .split_at(header.extra_data.len() - EXTRA_SEAL_LEN);
+
+    let mut unsealed = header.clone();
+    unsealed.extra_data = signed_extra.to_vec().into();
+    let digest = keccak(alloy_rlp::encode(&unsealed));
+
+    let recovery_id = RecoveryId::from_byte(seal[64]).context("invalid Clique seal recovery byte")?;
+    let signature = Signature::from_slice(&seal[..64]).context("invalid Clique seal signature")?;
+    let verifying_key = VerifyingKey::recover_from_prehash(&digest, &signature, recovery_id)
+        .context("could not recover Clique signer")?;
+
+    Ok(Address::from_public_key(&verifying_key))
+}
+
+/// Verifies `header`'s Clique seal on top of `parent`, applying its vote (if any) and
+/// sliding the recent-signers window. Mirrors go-ethereum's `clique.verifySeal` plus
+/// `snapshot.apply`.
+pub fn verify_seal(
+    config: &CliqueConfig,
+    state: &mut CliqueState,
+    header: &Header,
+    parent: &Header,
+) -> Result<()> {
+    ensure!(
+        header.timestamp >= parent.timestamp + config.period,
+        "block sealed before the configured Clique period elapsed"
+    );
+
+    let signer = recover_signer(header)?;
+    ensure!(
+        state.signers.contains(&signer),
+        "block sealed by an address outside the authorized Clique signer set"
+    );
+    ensure!(
+        !state.recents.contains(&signer),
+        "signer sealed a block too recently"
+    );
+
+    let expected_difficulty = match state.in_turn_signer(header.number) {
+        Some(in_turn) if *in_turn == signer => DIFF_IN_TURN,
+        _ => DIFF_NO_TURN,
+    };
+    ensure!(
+        header.difficulty == U256::from(expected_difficulty),
+        "block difficulty does not match the Clique in-turn/out-of-turn rule"
+    );
+
+    if is_checkpoint(config, header.number) {
+        // Checkpoint blocks carry the full authorized-signer set in `extraData`
+        // instead of a vote, and reset the pending votes accumulated over the epoch,
+        // per EIP-225.
+        let signers = checkpoint_signers(header)?;
+        ensure!(
+            signers == state.signers,
+            "checkpoint block's extraData signer list does not match the current authorized-signer set"
+        );
+        state.votes.clear();
+    } else if let Some(vote) = parse_vote(header, signer) {
+        // Apply the signer-list vote this header carries, if any (anything between
+        // the vanity prefix and the seal signature that isn't a checkpoint list).
+        apply_vote(state, vote);
+    }
+
+    state.recents.push_back(signer);
+    while state.recents.len() >= state.recent_signer_limit() {
+        state.recents.pop_front();
+    }
+
+    Ok(())
+}
+
+/// Whether `number` is a Clique checkpoint block, i.e. a multiple of `config.epoch`.
+/// `epoch == 0` disables checkpointing entirely.
+fn is_checkpoint(config: &CliqueConfig, number: u64) -> bool {
+    config.epoch != 0 && number % config.epoch == 0
+}
+
+/// Extracts the authorized-signer set packed into a checkpoint block's `extraData`,
+/// between the vanity prefix and the seal signature, as a sequence of 20-byte
+/// addresses.
+fn checkpoint_signers(header: &Header) -> Result<BTreeSet<Address>> {
+    ensure!(
+        header.extra_data.len() >= EXTRA_VANITY_LEN + EXTRA_SEAL_LEN,
+        "checkpoint extraData too short to contain a vanity prefix and seal"
+    );
+    let signers_packed =
+        &header.extra_data[EXTRA_VANITY_LEN..header.extra_data.len() - EXTRA_SEAL_LEN];
+    ensure!(
+        signers_packed.len() % ADDRESS_LEN == 0,
+        "checkpoint extraData signer list is not a multiple of the address length"
+    );
+    Ok(signers_packed
+        .chunks_exact(ADDRESS_LEN)
+        .map(Address::from_slice)
+        .collect())
+}
+
+fn parse_vote(header: &Header, proposer: Address) -> Option<Vote> {
+    // A voting header's `coinbase` names the subject account and `nonce` encodes
+    // authorize (0xffffffffffffffff) vs. deauthorize (0x0000000000000000), per
+    // EIP-225. A zero coinbase means "no vote this block".
+    if header.beneficiary == Address::ZERO {
+        return None;
+    }
+    let authorize = header.nonce == u64::MAX;
+    Some(Vote {
+        proposer,
+        subject: header.beneficiary,
+        authorize,
+    })
+}
+
+fn apply_vote(state: &mut CliqueState, vote: Vote) {
+    state.votes.retain(|v| v.proposer != vote.proposer || v.subject != vote.subject);
+    state.votes.push(vote.clone());
+
+    let tally = state
+        .votes
+        .iter()
+        .filter(|v| v.subject == vote.subject && v.authorize == vote.authorize)
+        .count();
+    if tally * 2 > state.signers.len() {
+        if vote.authorize {
+            state.signers.insert(vote.subject);
+        } else {
+            state.signers.remove(&vote.subject);
+            state.recents.retain(|s| *s != vote.subject);
+        }
+        state.votes.retain(|v| v.subject != vote.subject);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The seal/consensus engine a chain uses to authorize blocks, selected by the chain
+//! spec rather than assumed. zeth only ever validated PoW/post-merge Ethereum and
+//! OP-stack derivation; many L2 sequencer chains and testnets instead seal with a
+//! Clique-like proof-of-authority engine, so `finalization` needs to pick the right
+//! verifier per chain instead of hardcoding one.
+//!
+//! Mirrors the way OpenEthereum's `Spec` selects between `Ethash`/`Clique`/
+//! `AuthorityRound`/`InstantSeal` engines: the engine is part of the chain spec, so
+//! the guest enforces exactly the rules of the network the proof is for.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use zeth_primitives::block::Header;
+
+pub mod clique;
+
+use clique::{CliqueConfig, CliqueState};
+
+/// The consensus engine a [`crate::consts::ChainSpec`] seals its blocks with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsensusEngine {
+    /// Proof-of-work, or post-merge Ethereum where the seal is enforced by the
+    /// consensus layer and not re-checked here.
+    Ethash,
+    /// Clique proof-of-authority: an authorized-signer set that rotates via in-header
+    /// votes, with a round-robin in-turn/out-of-turn difficulty rule.
+    Clique(CliqueConfig),
+}
+
+impl Default for ConsensusEngine {
+    fn default() -> Self {
+        ConsensusEngine::Ethash
+    }
+}
+
+/// Mutable per-chain state a [`ConsensusEngine`] carries across headers: for
+/// [`ConsensusEngine::Ethash`] there is none, for
+/// [`ConsensusEngine::Clique`] the current authorized-signer set and the sliding
+/// window of recent signers (no signer may seal twice within `signer_count / 2 + 1`
+/// blocks).
+#[derive(Clone, Debug, Default)]
+pub enum ConsensusState {
+    #[default]
+    Ethash,
+    Clique(CliqueState),
+}
+
+impl ConsensusEngine {
+    /// Builds the initial [`ConsensusState`] for this engine, seeded with the chain
+    /// spec's genesis authorized-signer set for [`ConsensusEngine::Clique`].
+    pub fn initial_state(&self) -> ConsensusState {
+        match self {
+            ConsensusEngine::Ethash => ConsensusState::Ethash,
+            ConsensusEngine::Clique(config) => {
+                let genesis_signers = config.genesis_signers.clone();
+                ConsensusState::Clique(CliqueState::new(config.clone(), genesis_signers))
+            }
+        }
+    }
+
+    /// Verifies that `header` is validly sealed on top of `parent`, advancing `state`
+    /// in place (applying any signer votes `header` carries, sliding the recent-signer
+    /// window, etc.). A no-op for [`ConsensusEngine::Ethash`], where seal validity is
+    /// the consensus layer's responsibility.
+    pub fn verify_seal(&self, header: &Header, parent: &Header, state: &mut ConsensusState) -> Result<()> {
+        match (self, state) {
+            (ConsensusEngine::Ethash, ConsensusState::Ethash) => Ok(()),
+            (ConsensusEngine::Clique(config), ConsensusState::Clique(clique_state)) => {
+                clique::verify_seal(config, clique_state, header, parent)
+            }
+            _ => anyhow::bail!("consensus engine does not match chain spec's consensus state"),
+        }
+    }
+}
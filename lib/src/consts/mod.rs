@@ -0,0 +1,268 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chain configuration: the hardcoded networks zeth ships with, plus the
+//! [`spec_file`] loader for networks supplied at runtime.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use once_cell::sync::Lazy;
+use revm::primitives::SpecId;
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{keccak::keccak, Address, BlockNumber, B256};
+
+use crate::consensus::ConsensusEngine;
+
+pub mod spec_file;
+
+use spec_file::{genesis_accounts_root, GenesisAccount, GenesisStub};
+
+/// The maximum size in bytes of the `extraData` field allowed by the Ethereum protocol.
+pub const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+/// The lowest supported fork. zeth does not attempt to execute blocks older than this.
+pub const MIN_SPEC_ID: SpecId = SpecId::MERGE;
+
+/// The gas constants used by the [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) base fee
+/// adjustment algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip1559Constants {
+    pub base_fee_change_denominator: u64,
+    pub base_fee_max_increase_denominator: u64,
+    pub elasticity_multiplier: u64,
+}
+
+impl Default for Eip1559Constants {
+    fn default() -> Self {
+        Eip1559Constants {
+            base_fee_change_denominator: 8,
+            base_fee_max_increase_denominator: 2,
+            elasticity_multiplier: 2,
+        }
+    }
+}
+
+/// The condition under which a hardfork is activated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForkCondition {
+    /// Active starting from a given block number (inclusive).
+    Block(BlockNumber),
+    /// Active starting from a given block timestamp (inclusive).
+    Timestamp(u64),
+    /// Scheduled, but the activation point is not yet known.
+    TBD,
+    /// Never active on this chain.
+    Never,
+}
+
+impl ForkCondition {
+    /// Returns whether the condition is met for the given block.
+    pub fn active(&self, block_no: BlockNumber, timestamp: u64) -> bool {
+        match self {
+            ForkCondition::Block(no) => block_no >= *no,
+            ForkCondition::Timestamp(ts) => timestamp >= *ts,
+            ForkCondition::TBD | ForkCondition::Never => false,
+        }
+    }
+}
+
+/// The network a [`ChainSpec`] describes. Used to key on-disk RPC caches and select
+/// RPC endpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Network {
+    Ethereum,
+    Optimism,
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Network::Ethereum => write!(f, "ethereum"),
+            Network::Optimism => write!(f, "optimism"),
+        }
+    }
+}
+
+/// Describes the fork schedule and genesis parameters of a chain zeth can build or
+/// derive blocks for.
+///
+/// A [`ChainSpec`] is either one of the hardcoded constants below, or constructed at
+/// runtime from a JSON file via [`spec_file::load_chain_spec`]. Either way, the exact
+/// same value is committed into the guest's [`crate::input::Input`], so the proof binds
+/// to the fork schedule that was actually used during preflight: the host cannot swap in
+/// a different schedule once the guest is running.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainSpec {
+    name: String,
+    chain_id: u64,
+    hard_forks: BTreeMap<SpecId, ForkCondition>,
+    eip_1559_constants: Eip1559Constants,
+    #[serde(default)]
+    engine: ConsensusEngine,
+    /// The genesis header stub and preinstalled accounts a chain-spec file supplies,
+    /// e.g. predeploys on a new L2. `None`/empty for the hardcoded constants below,
+    /// which assume the real mainnet genesis instead of loading one.
+    #[serde(default)]
+    genesis: Option<GenesisStub>,
+    #[serde(default)]
+    accounts: BTreeMap<Address, GenesisAccount>,
+}
+
+impl ChainSpec {
+    /// Creates a new chain spec that activates a single, given spec ID from genesis.
+    pub fn new_single(
+        name: String,
+        chain_id: u64,
+        spec_id: SpecId,
+        eip_1559_constants: Eip1559Constants,
+    ) -> Self {
+        ChainSpec {
+            name,
+            chain_id,
+            hard_forks: BTreeMap::from([(spec_id, ForkCondition::Block(0))]),
+            eip_1559_constants,
+            engine: ConsensusEngine::default(),
+            genesis: None,
+            accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new chain spec with a full hardfork activation schedule.
+    pub fn new(
+        name: String,
+        chain_id: u64,
+        hard_forks: BTreeMap<SpecId, ForkCondition>,
+        eip_1559_constants: Eip1559Constants,
+    ) -> Self {
+        ChainSpec {
+            name,
+            chain_id,
+            hard_forks,
+            eip_1559_constants,
+            engine: ConsensusEngine::default(),
+            genesis: None,
+            accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Returns this spec with its consensus engine replaced, e.g. to describe a
+    /// Clique-sealed L2 or testnet instead of the PoW/post-merge default.
+    pub fn with_engine(mut self, engine: ConsensusEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Returns this spec with its genesis header stub and preinstalled accounts set,
+    /// e.g. the predeploys on a new L2. Used by [`spec_file::load_chain_spec`], since
+    /// the hardcoded constants below assume the real mainnet genesis instead.
+    pub fn with_genesis(mut self, genesis: GenesisStub, accounts: BTreeMap<Address, GenesisAccount>) -> Self {
+        self.genesis = Some(genesis);
+        self.accounts = accounts;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn gas_constants(&self) -> &Eip1559Constants {
+        &self.eip_1559_constants
+    }
+
+    pub fn engine(&self) -> &ConsensusEngine {
+        &self.engine
+    }
+
+    /// The genesis header stub this spec was loaded with, if it was loaded from a
+    /// chain-spec file rather than one of the hardcoded constants below.
+    pub fn genesis(&self) -> Option<&GenesisStub> {
+        self.genesis.as_ref()
+    }
+
+    /// The preinstalled genesis accounts this spec was loaded with, e.g. predeploys on
+    /// a new L2. Empty for the hardcoded constants below.
+    pub fn accounts(&self) -> &BTreeMap<Address, GenesisAccount> {
+        &self.accounts
+    }
+
+    /// Returns the [`SpecId`] active for the given block number and timestamp, i.e. the
+    /// latest hardfork whose activation condition is met.
+    pub fn active_fork(&self, block_no: BlockNumber, timestamp: u64) -> SpecId {
+        self.hard_forks
+            .iter()
+            .rev()
+            .find(|(_, cond)| cond.active(block_no, timestamp))
+            .map(|(spec_id, _)| *spec_id)
+            .unwrap_or(MIN_SPEC_ID)
+    }
+
+    /// A canonical digest of this spec, suitable for comparing/committing the fork
+    /// schedule (and genesis allocation) a proof was generated against. The genesis
+    /// accounts are folded in as their [`genesis_accounts_root`] hash; that hash covers
+    /// the whole account set and is recomputed on every call.
+    pub fn digest(&self) -> B256 {
+        #[derive(Serialize)]
+        struct DigestInput<'a> {
+            name: &'a str,
+            chain_id: u64,
+            hard_forks: &'a BTreeMap<SpecId, ForkCondition>,
+            eip_1559_constants: &'a Eip1559Constants,
+            engine: &'a ConsensusEngine,
+            genesis: &'a Option<GenesisStub>,
+            accounts_root: B256,
+        }
+
+        let digest_input = DigestInput {
+            name: &self.name,
+            chain_id: self.chain_id,
+            hard_forks: &self.hard_forks,
+            eip_1559_constants: &self.eip_1559_constants,
+            engine: &self.engine,
+            genesis: &self.genesis,
+            accounts_root: genesis_accounts_root(&self.accounts),
+        };
+        let encoded = bincode::serialize(&digest_input).expect("chain spec is serializable");
+        keccak(encoded).into()
+    }
+}
+
+pub static ETH_MAINNET_CHAIN_SPEC: Lazy<ChainSpec> = Lazy::new(|| {
+    ChainSpec::new(
+        "eth-mainnet".to_string(),
+        1,
+        BTreeMap::from([
+            (SpecId::MERGE, ForkCondition::Block(15537394)),
+            (SpecId::SHANGHAI, ForkCondition::Timestamp(1681338455)),
+            (SpecId::CANCUN, ForkCondition::Timestamp(1710338135)),
+        ]),
+        Eip1559Constants::default(),
+    )
+});
+
+pub static OP_MAINNET_CHAIN_SPEC: Lazy<ChainSpec> = Lazy::new(|| {
+    ChainSpec::new(
+        "op-mainnet".to_string(),
+        10,
+        BTreeMap::from([
+            (SpecId::MERGE, ForkCondition::Block(105235063)),
+            (SpecId::CANYON, ForkCondition::Timestamp(1704992401)),
+            (SpecId::ECOTONE, ForkCondition::Timestamp(1710361201)),
+        ]),
+        Eip1559Constants::default(),
+    )
+});
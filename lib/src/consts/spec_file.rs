@@ -0,0 +1,175 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loads a [`ChainSpec`] from a JSON file, so that proving a new network does not
+//! require a new hardcoded constant and a recompile of the host and the guest.
+//!
+//! The file format is modeled on OpenEthereum's `Spec` JSON: a genesis header stub, the
+//! preinstalled accounts and their balances/code/storage, a hardfork activation
+//! schedule keyed by block number or timestamp, and the EIP-1559 gas-schedule
+//! parameters.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use revm::primitives::SpecId;
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{keccak::keccak, Address, BlockNumber, B256, U256};
+
+use crate::consensus::{clique::CliqueConfig, ConsensusEngine};
+
+use super::{ChainSpec, Eip1559Constants, ForkCondition};
+
+/// A genesis account preinstalled by the chain spec, e.g. predeploys on an OP-stack L2.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisAccount {
+    #[serde(default)]
+    pub balance: U256,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub code: Option<zeth_primitives::Bytes>,
+    #[serde(default)]
+    pub storage: BTreeMap<B256, B256>,
+}
+
+/// The genesis header stub a chain spec file supplies: the fields zeth needs to seed
+/// the parent header of the first block it ever builds on this chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisStub {
+    pub state_root: B256,
+    pub gas_limit: u64,
+    pub timestamp: u64,
+    pub extra_data: zeth_primitives::Bytes,
+    pub base_fee_per_gas: Option<U256>,
+}
+
+/// The on-disk JSON representation of a [`ChainSpec`]. Hardforks are keyed by name so
+/// the file stays human-editable; [`TryFrom`] maps each name to the [`SpecId`] zeth
+/// already branches on in `execution`/`finalization`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChainSpecFile {
+    pub name: String,
+    pub chain_id: u64,
+    pub genesis: GenesisStub,
+    #[serde(default)]
+    pub accounts: BTreeMap<Address, GenesisAccount>,
+    /// Maps a hardfork name (e.g. `"shanghai"`, `"cancun"`) to the block number or
+    /// timestamp at which it activates. Forks omitted here are treated as `Never`.
+    pub hard_forks: BTreeMap<String, ForkConditionFile>,
+    #[serde(default)]
+    pub eip_1559_constants: Eip1559Constants,
+    /// The consensus engine sealing this chain's blocks. Omitted for PoW/post-merge
+    /// Ethereum-style chains; set for Clique-sealed L2s and testnets.
+    #[serde(default)]
+    pub clique: Option<CliqueConfigFile>,
+}
+
+/// On-disk encoding of a Clique engine selection; see
+/// [`crate::consensus::clique::CliqueConfig`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CliqueConfigFile {
+    pub period: u64,
+    pub epoch: u64,
+    /// The genesis authorized-signer set, as packed into the genesis `extraData`
+    /// checkpoint.
+    pub signers: Vec<Address>,
+}
+
+/// The JSON-friendly encoding of a [`ForkCondition`]: exactly one of the two fields must
+/// be set.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct ForkConditionFile {
+    pub block: Option<BlockNumber>,
+    pub timestamp: Option<u64>,
+}
+
+fn parse_spec_id(name: &str) -> Result<SpecId> {
+    parse_fork_name(name)
+}
+
+/// Maps a hardfork name to the [`SpecId`] zeth already branches on throughout
+/// `execution` and `finalization`; keep this list in sync with that EIP/gas-schedule
+/// logic. Case-insensitive so it accepts both zeth's own chain-spec-file convention
+/// (lowercase, e.g. `"shanghai"`) and upstream `execution-spec-tests` fixtures' `network`
+/// field (capitalized, e.g. `"Shanghai"`).
+pub(crate) fn parse_fork_name(name: &str) -> Result<SpecId> {
+    let spec_id = match name.to_ascii_lowercase().as_str() {
+        "merge" | "paris" => SpecId::MERGE,
+        "shanghai" => SpecId::SHANGHAI,
+        "cancun" => SpecId::CANCUN,
+        "canyon" => SpecId::CANYON,
+        "ecotone" => SpecId::ECOTONE,
+        other => anyhow::bail!("unknown hardfork name: {other}"),
+    };
+    Ok(spec_id)
+}
+
+impl TryFrom<ChainSpecFile> for ChainSpec {
+    type Error = anyhow::Error;
+
+    fn try_from(file: ChainSpecFile) -> Result<Self> {
+        let mut hard_forks = BTreeMap::new();
+        for (name, condition) in &file.hard_forks {
+            let spec_id = parse_spec_id(name)
+                .with_context(|| format!("invalid hardfork entry {name:?}"))?;
+            let condition = match (condition.block, condition.timestamp) {
+                (Some(block), None) => ForkCondition::Block(block),
+                (None, Some(timestamp)) => ForkCondition::Timestamp(timestamp),
+                (None, None) => anyhow::bail!("hardfork {name:?} has no activation point"),
+                (Some(_), Some(_)) => {
+                    anyhow::bail!("hardfork {name:?} sets both block and timestamp")
+                }
+            };
+            hard_forks.insert(spec_id, condition);
+        }
+        anyhow::ensure!(!hard_forks.is_empty(), "chain spec file defines no hardforks");
+
+        let spec = ChainSpec::new(file.name, file.chain_id, hard_forks, file.eip_1559_constants)
+            .with_genesis(file.genesis, file.accounts);
+        let spec = match file.clique {
+            Some(clique) => spec.with_engine(ConsensusEngine::Clique(CliqueConfig {
+                period: clique.period,
+                epoch: clique.epoch,
+                genesis_signers: clique.signers,
+            })),
+            None => spec,
+        };
+
+        Ok(spec)
+    }
+}
+
+/// Loads and parses a [`ChainSpec`] from a JSON file at `path`.
+///
+/// The resulting spec deserializes into the exact same [`ChainSpec`] type as the
+/// hardcoded constants, so every consumer (`fetch_op_blocks`, `DeriveMachine::new`,
+/// `EthereumStrategy::build_from`) can be handed either one interchangeably.
+pub fn load_chain_spec(path: impl AsRef<Path>) -> Result<ChainSpec> {
+    let path = path.as_ref();
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read chain spec file {}", path.display()))?;
+    let file: ChainSpecFile = serde_json::from_str(&raw)
+        .with_context(|| format!("could not parse chain spec file {}", path.display()))?;
+    file.try_into()
+        .with_context(|| format!("invalid chain spec file {}", path.display()))
+}
+
+/// A collision-resistant fingerprint of the account preinstalls: the keccak hash of the
+/// whole account map's bincode encoding, used to bind the genesis allocation into
+/// [`ChainSpec::digest`]. It is not cached; every call hashes the full map.
+pub fn genesis_accounts_root(accounts: &BTreeMap<Address, GenesisAccount>) -> B256 {
+    let encoded = bincode::serialize(accounts).expect("genesis accounts are serializable");
+    keccak(encoded).into()
+}
@@ -0,0 +1,84 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encodes the blocks zeth builds or derives as standard RLP, the same encoding every
+//! other Ethereum tool (import, re-verification, block explorers) consumes. Nothing
+//! else in zeth needs this: the guest only ever needs a block's hash, but a user who
+//! wants to feed a proven block back into an independent execution client needs the
+//! canonical bytes, not just the hash the proof commits to.
+
+use std::{io::Write, path::Path};
+
+use alloy_rlp::Encodable;
+use anyhow::{Context, Result};
+use zeth_primitives::{block::Header, transactions::TxEssence};
+
+/// A block exactly as an external client would encode it on the wire: the header,
+/// followed by the ordered transaction list, followed by the uncle (ommer) headers --
+/// an RLP list of three elements, per the Ethereum wire format.
+pub struct ExportedBlock<'a, E: TxEssence> {
+    pub header: &'a Header,
+    pub transactions: &'a [E],
+    pub ommers: &'a [Header],
+}
+
+impl<'a, E: TxEssence> Encodable for ExportedBlock<'a, E> {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        alloy_rlp::Header {
+            list: true,
+            payload_length: self.header.length()
+                + self.transactions.length()
+                + self.ommers.length(),
+        }
+        .encode(out);
+        self.header.encode(out);
+        self.transactions.encode(out);
+        self.ommers.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length =
+            self.header.length() + self.transactions.length() + self.ommers.length();
+        alloy_rlp::length_of_length(payload_length) + payload_length
+    }
+}
+
+/// RLP-encodes `header`/`transactions`/`ommers` into the standard block wire format.
+pub fn encode_block<E: TxEssence>(header: &Header, transactions: &[E], ommers: &[Header]) -> Vec<u8> {
+    let block = ExportedBlock {
+        header,
+        transactions,
+        ommers,
+    };
+    alloy_rlp::encode(&block)
+}
+
+/// Appends the RLP encoding of one block to `path`, one block per call. Concatenated
+/// RLP block streams are what `geth import`/`erigon import` expect, so repeated calls
+/// against the same path build up an importable chain segment.
+pub fn export_block<E: TxEssence>(
+    path: impl AsRef<Path>,
+    header: &Header,
+    transactions: &[E],
+    ommers: &[Header],
+) -> Result<()> {
+    let path = path.as_ref();
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("could not open RLP export file {}", path.display()))?;
+    file.write_all(&encode_block(header, transactions, ommers))
+        .with_context(|| format!("could not write RLP export file {}", path.display()))
+}
@@ -0,0 +1,46 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Finalizes a built block's header: besides the state root and the other fields
+//! `execution` leaves for the caller to fill in, a header is only valid if it is
+//! properly sealed. Which rules that means depends on the chain's consensus engine, so
+//! seal verification is delegated to [`crate::consensus::ConsensusEngine`] rather than
+//! assumed to be PoW/post-merge Ethereum.
+
+use anyhow::{ensure, Context, Result};
+use zeth_primitives::block::Header;
+
+use crate::consensus::{ConsensusEngine, ConsensusState};
+use crate::consts::MAX_EXTRA_DATA_BYTES;
+
+/// Verifies a single header against its parent: `extraData` size, and the seal itself
+/// via the chain's [`ConsensusEngine`]. For [`ConsensusEngine::Clique`] chains this also
+/// advances `consensus_state` with the header's signer-list vote, so the caller must
+/// verify headers in order and reuse the same state across a derivation/build run.
+pub fn finalize_header(
+    header: &Header,
+    parent: &Header,
+    engine: &ConsensusEngine,
+    consensus_state: &mut ConsensusState,
+) -> Result<()> {
+    ensure!(
+        header.extra_data.len() <= MAX_EXTRA_DATA_BYTES || matches!(engine, ConsensusEngine::Clique(_)),
+        "extraData exceeds the maximum allowed size"
+    );
+    ensure!(header.number == parent.number + 1, "header does not extend its parent");
+
+    engine
+        .verify_seal(header, parent, consensus_state)
+        .context("seal verification failed")
+}
@@ -0,0 +1,87 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The guest's committed input: everything a [`crate::builder::BlockBuilderStrategy`]
+//! needs to build the next block(s) on top of a known parent, without any further host
+//! interaction.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{
+    block::{Block, Header},
+    transactions::TxEssence,
+    Address,
+};
+
+use crate::consts::{spec_file::GenesisAccount, ChainSpec};
+
+/// Everything the guest needs to build the next block(s) on top of `parent_header`.
+///
+/// The [`ChainSpec`] travels inside `Input` rather than as a hardcoded guest constant
+/// or a host-supplied argument the guest simply trusts, so the proof binds to the
+/// exact fork schedule (and consensus engine) the host resolved during preflight: the
+/// host cannot swap in a different chain once the guest is running.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Input<E: TxEssence> {
+    /// The chain spec the guest must enforce while building from this input.
+    pub chain_spec: ChainSpec,
+    /// Header of the block this input's blocks are built on top of.
+    pub parent_header: Header,
+    /// The parent block's state, keyed by address.
+    pub pre_state: BTreeMap<Address, GenesisAccount>,
+    /// The block(s) to build, in order.
+    pub blocks: Vec<Block<E>>,
+}
+
+impl<E: TxEssence> Input<E> {
+    /// Builds the [`Input`] a [`crate::builder::BlockBuilderStrategy`] expects from an
+    /// `execution-spec-tests` fixture unit: the fixture's parent header and pre-state
+    /// allocation, plus its block sequence.
+    #[cfg(any(test, feature = "conformance"))]
+    pub fn from_fixture(
+        parent_header: Header,
+        pre_state: BTreeMap<Address, crate::conformance::fixture::PreStateAccount>,
+        blocks: Vec<Block<E>>,
+    ) -> anyhow::Result<Self> {
+        let pre_state = pre_state
+            .into_iter()
+            .map(|(address, account)| {
+                let code = (!account.code.is_empty()).then_some(account.code);
+                let genesis_account = GenesisAccount {
+                    balance: account.balance,
+                    nonce: account.nonce,
+                    code,
+                    storage: account.storage,
+                };
+                (address, genesis_account)
+            })
+            .collect();
+
+        Ok(Input {
+            // Fixtures pin a single active fork rather than a schedule; the caller
+            // builds against `BlockchainTestUnit::chain_spec` directly instead of
+            // relying on this field, so any single-fork placeholder is sufficient.
+            chain_spec: ChainSpec::new_single(
+                "conformance".to_string(),
+                parent_header.chain_id().unwrap_or(1),
+                crate::consts::MIN_SPEC_ID,
+                Default::default(),
+            ),
+            parent_header,
+            pre_state,
+            blocks,
+        })
+    }
+}
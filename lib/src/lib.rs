@@ -19,9 +19,15 @@ pub mod host;
 
 pub mod auth_db;
 pub mod block_builder;
+pub mod builder;
+#[cfg(any(test, feature = "conformance"))]
+pub mod conformance;
+pub mod consensus;
 pub mod consts;
 pub mod derivation;
 pub mod execution;
+pub mod export;
 pub mod finalization;
 pub mod initialization;
 pub mod input;
+pub mod output;
@@ -0,0 +1,53 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The result a [`crate::builder::BlockBuilderStrategy`] commits, so a verifier can
+//! check the built block's hash and which chain spec the guest enforced while
+//! building it, without re-running the build itself.
+
+use serde::{Deserialize, Serialize};
+use zeth_primitives::{block::Header, B256};
+
+/// The outcome of `crate::builder::BlockBuilderStrategy::build_from`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockBuildOutput {
+    /// The block built successfully.
+    SUCCESS {
+        /// Hash of the newly built block.
+        new_block_hash: B256,
+        /// The newly built block's header.
+        new_block_head: Header,
+        /// The newly built block's post-state root, as its header states it.
+        new_block_state: B256,
+        /// Digest of the [`crate::consts::ChainSpec`] the guest enforced while
+        /// building this block (see [`crate::consts::ChainSpec::digest`]), so a
+        /// verifier can tell which network a proof is for without re-deriving the
+        /// whole spec.
+        chain_spec_digest: B256,
+    },
+    /// No block was built, e.g. because the input held no blocks. Invalid headers
+    /// or seals make `build_from` return an error instead.
+    FAILURE,
+}
+
+impl BlockBuildOutput {
+    /// The post-state root of a successful build. Meaningless for
+    /// [`BlockBuildOutput::FAILURE`].
+    pub fn state_root(&self) -> B256 {
+        match self {
+            BlockBuildOutput::SUCCESS { new_block_state, .. } => *new_block_state,
+            BlockBuildOutput::FAILURE => B256::ZERO,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs every execution-spec-tests blockchain-test fixture under
+//! `ZETH_CONFORMANCE_FIXTURES` (or `lib/tests/fixtures/blockchain_tests` if unset)
+//! through `EthereumStrategy::build_from`, host-side. Fixtures for forks zeth doesn't
+//! implement are skipped; see `SUPPORTED_FORKS` below.
+//!
+//! Download fixtures with, e.g.:
+//!   `git clone --depth 1 https://github.com/ethereum/tests lib/tests/fixtures`
+
+use revm::primitives::SpecId;
+use zeth_lib::conformance::{load_fixtures, run_unit};
+
+const SUPPORTED_FORKS: &[SpecId] = &[SpecId::MERGE, SpecId::SHANGHAI, SpecId::CANCUN];
+
+fn fixtures_dir() -> std::path::PathBuf {
+    std::env::var("ZETH_CONFORMANCE_FIXTURES")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|_| {
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/blockchain_tests")
+        })
+}
+
+#[test]
+fn blockchain_tests() {
+    let dir = fixtures_dir();
+    if !dir.exists() {
+        eprintln!(
+            "skipping conformance suite: fixtures directory {} not found",
+            dir.display()
+        );
+        return;
+    }
+
+    let units = load_fixtures(&dir, SUPPORTED_FORKS).expect("could not load fixtures");
+    assert!(!units.is_empty(), "no fixtures found for supported forks in {}", dir.display());
+
+    let mut failures = Vec::new();
+    for unit in &units {
+        if let Some(result) = run_unit(unit, SUPPORTED_FORKS).expect("could not run fixture unit") {
+            if !result.is_pass() {
+                failures.push(format!("{result:?}"));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} conformance case(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}